@@ -0,0 +1,185 @@
+use core::{
+    cell::UnsafeCell,
+    fmt::{self, Debug},
+    mem::replace,
+    ops::Deref,
+};
+
+use crate::{panic_poisoned, State};
+
+/// Like [`LazyMut`](crate::LazyMut), but also offers shared-reference read
+/// access once initialized, via [`force`](Self::force) and [`Deref`]
+///
+/// Unlike [`LazyMut`](crate::LazyMut), this does not implement
+/// [`Default`], but it keeps [`get`](Self::get) for mutation through
+/// `&mut self`
+pub struct LazyMutCell<T, F = fn() -> T> {
+    state: UnsafeCell<State<T, F>>,
+}
+
+impl<T: Debug, F> Debug for LazyMutCell<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut t = f.debug_tuple("LazyMutCell");
+        match self.try_get() {
+            Some(val) => t.field(val),
+            None => t.field(&format_args!("<uninit>")),
+        };
+        t.finish()
+    }
+}
+
+impl<T, F> LazyMutCell<T, F> {
+    /// Creates a new [`LazyMutCell`]
+    pub const fn new(f: F) -> Self {
+        Self { state: UnsafeCell::new(State::Uninit(f)) }
+    }
+
+    /// Try get inner value reference
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lazymut::LazyMutCell;
+    /// let mut lazy_cell = LazyMutCell::new(|| 3);
+    ///
+    /// assert_eq!(lazy_cell.try_get(), None);
+    /// assert_eq!(lazy_cell.force(), &3);
+    /// assert_eq!(lazy_cell.try_get(), Some(&3));
+    /// ```
+    pub fn try_get(&self) -> Option<&T> {
+        unsafe { &*self.state.get() }.try_get()
+    }
+
+    /// Try get inner value mut reference
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lazymut::LazyMutCell;
+    /// let mut lazy_cell = LazyMutCell::new(|| 3);
+    ///
+    /// assert_eq!(lazy_cell.try_get_mut(), None);
+    /// assert_eq!(lazy_cell.get(), &mut 3);
+    /// assert_eq!(lazy_cell.try_get_mut(), Some(&mut 3));
+    /// ```
+    pub fn try_get_mut(&mut self) -> Option<&mut T> {
+        self.state.get_mut().try_get_mut()
+    }
+
+    /// Returns `true` if the initializer has previously panicked, leaving
+    /// this instance poisoned
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lazymut::LazyMutCell;
+    /// let lazy_cell = LazyMutCell::new(|| 3);
+    ///
+    /// assert!(!lazy_cell.is_poisoned());
+    /// assert_eq!(lazy_cell.force(), &3);
+    /// assert!(!lazy_cell.is_poisoned());
+    /// ```
+    pub fn is_poisoned(&self) -> bool {
+        matches!(unsafe { &*self.state.get() }, State::Poisoned)
+    }
+
+    /// Returns the value if initialized, otherwise returns back the
+    /// uncalled initializer
+    ///
+    /// # Panics
+    ///
+    /// Panics if state is poisoned
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lazymut::LazyMutCell;
+    /// let lazy_cell = LazyMutCell::new(|| 3);
+    ///
+    /// assert_eq!(lazy_cell.force(), &3);
+    /// assert_eq!(lazy_cell.into_value().ok(), Some(3));
+    /// ```
+    pub fn into_value(self) -> Result<T, F> {
+        match self.state.into_inner() {
+            State::Uninit(f) => Err(f),
+            State::Poisoned => panic_poisoned(),
+            State::Inited(val) => Ok(val),
+        }
+    }
+}
+
+impl<T, F: FnOnce() -> T> LazyMutCell<T, F> {
+    /// Force initialization and get a shared value reference
+    ///
+    /// # Panics
+    ///
+    /// Panics if state is poisoned or initializer panics
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lazymut::LazyMutCell;
+    /// let lazy_cell = LazyMutCell::new(|| 3);
+    ///
+    /// assert_eq!(lazy_cell.force(), &3);
+    /// assert_eq!(lazy_cell.force(), &3);
+    /// ```
+    pub fn force(&self) -> &T {
+        let state = unsafe { &*self.state.get() };
+        match state {
+            State::Inited(val) => val,
+            State::Poisoned => panic_poisoned(),
+            State::Uninit(_) => self.really_init(),
+        }
+    }
+
+    /// Get mutable value reference or initialize value
+    ///
+    /// # Panics
+    ///
+    /// Panics if state is poisoned or initializer panic
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lazymut::LazyMutCell;
+    /// let mut lazy_cell = LazyMutCell::new(|| vec![1]);
+    ///
+    /// assert_eq!(lazy_cell.get(), &mut vec![1]);
+    /// lazy_cell.get().push(2);
+    /// assert_eq!(lazy_cell.get(), &mut vec![1, 2]);
+    /// ```
+    pub fn get(&mut self) -> &mut T {
+        self.state.get_mut().get_or_init()
+    }
+
+    /// Installs `State::Poisoned` before running `f`, so a panic in `f`
+    /// leaves the cell in a well-defined poisoned state rather than
+    /// half-initialized, and only overwrites it with `State::Inited` once
+    /// `f` returns
+    #[cold]
+    fn really_init(&self) -> &T {
+        let state = unsafe { &mut *self.state.get() };
+        let State::Uninit(f) = replace(state, State::Poisoned) else { unreachable!() };
+        *state = State::Inited(f());
+        state.try_get().unwrap()
+    }
+}
+
+impl<T, F: FnOnce() -> T> Deref for LazyMutCell<T, F> {
+    type Target = T;
+
+    /// Forces initialization via [`force`](Self::force)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lazymut::LazyMutCell;
+    /// let lazy_cell = LazyMutCell::new(|| 3);
+    ///
+    /// assert_eq!(*lazy_cell, 3);
+    /// ```
+    fn deref(&self) -> &T {
+        self.force()
+    }
+}
@@ -3,6 +3,11 @@
 
 use core::{fmt::{self, Debug}, mem::replace};
 
+mod cell;
+mod lock;
+pub use cell::LazyMutCell;
+pub use lock::LazyMutLock;
+
 #[doc = include_str!("../README.md")]
 #[derive(Default)]
 pub struct LazyMut<T, F = fn() -> T> {
@@ -88,6 +93,104 @@ impl<T, F> LazyMut<T, F> {
             State::Inited(val) => Some(val),
         }
     }
+
+    /// Returns the value if initialized, otherwise returns back the
+    /// uncalled initializer
+    ///
+    /// Unlike [`into_inner`](Self::into_inner), this does not discard the
+    /// initializer `F` when the value was never forced, so it can be
+    /// reused (e.g. handed to another [`LazyMut`])
+    ///
+    /// # Panics
+    ///
+    /// Panics if state is poisoned
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lazymut::LazyMut;
+    /// let mut lazy_mut = LazyMut::new(|| 3);
+    ///
+    /// assert_eq!(lazy_mut.get(), &mut 3);
+    /// assert_eq!(lazy_mut.into_value().ok(), Some(3));
+    /// ```
+    ///
+    /// ```
+    /// # use lazymut::LazyMut;
+    /// let lazy_mut: LazyMut<i32, _> = LazyMut::new(|| 3i32);
+    ///
+    /// assert!(lazy_mut.into_value().is_err());
+    /// ```
+    pub fn into_value(self) -> Result<T, F> {
+        match self.state {
+            State::Uninit(f) => Err(f),
+            State::Poisoned => panic_poisoned(),
+            State::Inited(val) => Ok(val),
+        }
+    }
+
+    /// Returns `true` if the initializer has previously panicked, leaving
+    /// this instance poisoned
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lazymut::LazyMut;
+    /// let mut lazy_mut = LazyMut::new(|| 3);
+    ///
+    /// assert!(!lazy_mut.is_poisoned());
+    /// assert_eq!(lazy_mut.get(), &mut 3);
+    /// assert!(!lazy_mut.is_poisoned());
+    /// ```
+    pub const fn is_poisoned(&self) -> bool {
+        self.state.is_poisoned()
+    }
+
+    /// Returns the value without panicking if poisoned
+    ///
+    /// Unlike [`into_inner`](Self::into_inner), a poisoned instance yields
+    /// [`Err(PoisonError)`](PoisonError) instead of unwinding
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lazymut::LazyMut;
+    /// let mut lazy_mut = LazyMut::new(|| 3);
+    ///
+    /// assert_eq!(lazy_mut.get(), &mut 3);
+    /// assert_eq!(lazy_mut.try_into_inner(), Ok(Some(3)));
+    /// ```
+    pub fn try_into_inner(self) -> Result<Option<T>, PoisonError> {
+        match self.state {
+            State::Uninit(_) => Ok(None),
+            State::Poisoned => Err(PoisonError),
+            State::Inited(val) => Ok(Some(val)),
+        }
+    }
+
+    /// Resets this instance back to an uninitialized state with a new
+    /// initializer, recovering a poisoned instance for reuse
+    ///
+    /// Any previously stored value or uncalled initializer is dropped
+    ///
+    /// Note that the replacement initializer must be the same `F`, so this
+    /// only accepts a fresh closure when `F` is a `fn` pointer rather than
+    /// a unique closure type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lazymut::LazyMut;
+    /// let mut lazy_mut = LazyMut::<i32, fn() -> i32>::new(|| 3);
+    ///
+    /// assert_eq!(lazy_mut.get(), &mut 3);
+    /// lazy_mut.reinit(|| 4);
+    /// assert_eq!(lazy_mut.try_get(), None);
+    /// assert_eq!(lazy_mut.get(), &mut 4);
+    /// ```
+    pub fn reinit(&mut self, f: F) {
+        self.state = State::Uninit(f);
+    }
 }
 
 impl<T, F: FnOnce() -> T> LazyMut<T, F> {
@@ -159,6 +262,21 @@ impl<T, F> State<T, F> {
             None
         }
     }
+
+    const fn is_poisoned(&self) -> bool {
+        matches!(self, Self::Poisoned)
+    }
+}
+
+/// Error returned when a [`LazyMut`] has been poisoned by a panicking
+/// initializer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoisonError;
+
+impl fmt::Display for PoisonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LazyMut instance has previously been poisoned")
+    }
 }
 
 #[cold]
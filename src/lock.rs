@@ -0,0 +1,209 @@
+use core::{
+    cell::UnsafeCell,
+    fmt::{self, Debug},
+    hint::spin_loop,
+    mem::replace,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use crate::{panic_poisoned, State};
+
+const UNINIT: u8 = 0;
+const RUNNING: u8 = 1;
+const READY: u8 = 2;
+
+/// A thread-safe sibling of [`LazyMut`](crate::LazyMut)
+///
+/// Initialization is synchronized across threads on first access through
+/// `&self`, after which `&mut self` hands out `&mut T` directly, bypassing
+/// the lock since `&mut self` already proves exclusive access
+pub struct LazyMutLock<T, F = fn() -> T> {
+    state: AtomicU8,
+    data: UnsafeCell<State<T, F>>,
+}
+
+unsafe impl<T: Send, F: Send> Send for LazyMutLock<T, F> {}
+unsafe impl<T: Send + Sync, F: Send> Sync for LazyMutLock<T, F> {}
+
+impl<T: Debug, F> Debug for LazyMutLock<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut t = f.debug_tuple("LazyMutLock");
+        match self.try_get() {
+            Some(val) => t.field(val),
+            None => t.field(&format_args!("<uninit>")),
+        };
+        t.finish()
+    }
+}
+
+impl<T, F> LazyMutLock<T, F> {
+    /// Creates a new [`LazyMutLock`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lazymut::LazyMutLock;
+    /// let lazy_lock: LazyMutLock<i32, _> = LazyMutLock::new(|| 3);
+    ///
+    /// assert_eq!(lazy_lock.try_get(), None);
+    /// ```
+    pub const fn new(f: F) -> Self {
+        Self {
+            state: AtomicU8::new(UNINIT),
+            data: UnsafeCell::new(State::Uninit(f)),
+        }
+    }
+
+    /// Try get inner value reference without blocking on a concurrent
+    /// initialization
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lazymut::LazyMutLock;
+    /// let mut lazy_lock = LazyMutLock::new(|| 3);
+    ///
+    /// assert_eq!(lazy_lock.try_get(), None);
+    /// assert_eq!(lazy_lock.get_mut(), &mut 3);
+    /// assert_eq!(lazy_lock.try_get(), Some(&3));
+    /// ```
+    pub fn try_get(&self) -> Option<&T> {
+        if self.state.load(Ordering::Acquire) != READY {
+            return None;
+        }
+        unsafe { &*self.data.get() }.try_get()
+    }
+
+    /// Returns `true` if the initializer has previously panicked, leaving
+    /// this instance poisoned
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lazymut::LazyMutLock;
+    /// let mut lazy_lock = LazyMutLock::new(|| 3);
+    ///
+    /// assert!(!lazy_lock.is_poisoned());
+    /// assert_eq!(lazy_lock.get_mut(), &mut 3);
+    /// assert!(!lazy_lock.is_poisoned());
+    /// ```
+    pub fn is_poisoned(&self) -> bool {
+        self.state.load(Ordering::Acquire) == READY
+            && matches!(unsafe { &*self.data.get() }, State::Poisoned)
+    }
+
+    /// Returns the value without requiring `F: FnOnce() -> T`, consuming
+    /// `self`
+    ///
+    /// # Panics
+    ///
+    /// Panics if state is poisoned
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lazymut::LazyMutLock;
+    /// let mut lazy_lock = LazyMutLock::new(|| 3);
+    ///
+    /// assert_eq!(lazy_lock.get_mut(), &mut 3);
+    /// assert_eq!(lazy_lock.into_inner().ok(), Some(3));
+    /// ```
+    pub fn into_inner(self) -> Result<T, F> {
+        match self.data.into_inner() {
+            State::Uninit(f) => Err(f),
+            State::Poisoned => panic_poisoned(),
+            State::Inited(val) => Ok(val),
+        }
+    }
+}
+
+impl<T, F: FnOnce() -> T> LazyMutLock<T, F> {
+    /// Get a shared value reference, initializing it if necessary
+    ///
+    /// If another thread is currently initializing, this spins until that
+    /// thread is finished
+    ///
+    /// # Panics
+    ///
+    /// Panics if state is poisoned or initializer panics
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lazymut::LazyMutLock;
+    /// let lazy_lock = LazyMutLock::new(|| 3);
+    ///
+    /// assert_eq!(lazy_lock.get(), &3);
+    /// assert_eq!(lazy_lock.get(), &3);
+    /// ```
+    pub fn get(&self) -> &T {
+        self.ensure_init();
+        match unsafe { &*self.data.get() } {
+            State::Inited(val) => val,
+            State::Poisoned => panic_poisoned(),
+            State::Uninit(_) => unreachable!(),
+        }
+    }
+
+    /// Get a mutable value reference, initializing it if necessary
+    ///
+    /// Since `&mut self` proves there is no concurrent access, this is the
+    /// thread-safe `force_mut`: it bypasses the synchronization lock
+    /// entirely and initializes directly
+    ///
+    /// # Panics
+    ///
+    /// Panics if state is poisoned or initializer panics
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lazymut::LazyMutLock;
+    /// let mut lazy_lock = LazyMutLock::new(|| vec![1]);
+    ///
+    /// assert_eq!(lazy_lock.get_mut(), &mut vec![1]);
+    /// lazy_lock.get_mut().push(2);
+    /// assert_eq!(lazy_lock.get_mut(), &mut vec![1, 2]);
+    /// ```
+    pub fn get_mut(&mut self) -> &mut T {
+        let guard = ReadyOnDrop(&self.state);
+        let val = self.data.get_mut().get_or_init();
+        drop(guard);
+        val
+    }
+
+    fn ensure_init(&self) {
+        loop {
+            match self.state.compare_exchange(
+                UNINIT,
+                RUNNING,
+                Ordering::Acquire,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => {
+                    let guard = ReadyOnDrop(&self.state);
+                    let data = unsafe { &mut *self.data.get() };
+                    let this = replace(data, State::Poisoned);
+                    let State::Uninit(f) = this else { unreachable!() };
+                    *data = State::Inited(f());
+                    drop(guard);
+                    return;
+                },
+                Err(RUNNING) => spin_loop(),
+                Err(READY) => return,
+                Err(_) => unreachable!(),
+            }
+        }
+    }
+}
+
+/// Flips the synchronization state back to `READY` when dropped, including
+/// during unwinding, so a panicking initializer does not leave other threads
+/// spinning forever against the already-poisoned data
+struct ReadyOnDrop<'a>(&'a AtomicU8);
+
+impl Drop for ReadyOnDrop<'_> {
+    fn drop(&mut self) {
+        self.0.store(READY, Ordering::Release);
+    }
+}